@@ -0,0 +1,62 @@
+use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
+use soroban_sdk::{Address, Env};
+
+/// Reads the live allowance `from -> spender`, treating an expired grant as zero.
+pub fn read_allowance(env: &Env, from: Address, spender: Address) -> AllowanceValue {
+    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    if let Some(allowance) = env.storage().persistent().get::<_, AllowanceValue>(&key) {
+        if allowance.expiration_ledger < env.ledger().sequence() {
+            AllowanceValue {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            }
+        } else {
+            allowance
+        }
+    } else {
+        AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        }
+    }
+}
+
+/// Writes a new allowance. `expiration_ledger` must not be in the past unless
+/// the amount is being cleared to zero.
+pub fn write_allowance(
+    env: &Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic!("expiration_ledger is in the past");
+    }
+
+    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().persistent().set(&key, &allowance);
+}
+
+/// Decrements the live allowance by `amount`, panicking if it is expired or insufficient.
+pub fn spend_allowance(env: &Env, from: Address, spender: Address, amount: i128) {
+    crate::balance::check_nonnegative_amount(amount);
+
+    let allowance = read_allowance(env, from.clone(), spender.clone());
+    if allowance.amount < amount {
+        panic!("insufficient allowance");
+    }
+    if amount > 0 {
+        write_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::{Address, Env};
+
+/// Every entry point that takes an amount must call this first — a negative
+/// amount would otherwise flip the sign of the balance arithmetic below and
+/// let a caller credit or debit an arbitrary account.
+pub fn check_nonnegative_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed");
+    }
+}
+
+pub fn read_balance(env: &Env, addr: Address) -> i128 {
+    let key = DataKey::Balance(addr);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn write_balance(env: &Env, addr: Address, amount: i128) {
+    let key = DataKey::Balance(addr);
+    env.storage().persistent().set(&key, &amount);
+}
+
+pub fn receive_balance(env: &Env, addr: Address, amount: i128) {
+    if !is_authorized(env, addr.clone()) {
+        panic!("account is deauthorized");
+    }
+    let balance = read_balance(env, addr.clone());
+    write_balance(env, addr, balance + amount);
+}
+
+pub fn spend_balance(env: &Env, addr: Address, amount: i128) {
+    if !is_authorized(env, addr.clone()) {
+        panic!("account is deauthorized");
+    }
+    spend_balance_no_authorization_check(env, addr, amount);
+}
+
+/// Debits `addr` without the freeze check `spend_balance` applies. Only
+/// `clawback` should use this — it exists precisely to pull tokens out of
+/// accounts the admin has frozen.
+pub fn spend_balance_no_authorization_check(env: &Env, addr: Address, amount: i128) {
+    let balance = read_balance(env, addr.clone());
+    if balance < amount {
+        panic!("insufficient balance");
+    }
+    write_balance(env, addr, balance - amount);
+}
+
+/// Freeze flags default to authorized so existing holders aren't affected
+/// until an admin explicitly deauthorizes them.
+pub fn is_authorized(env: &Env, addr: Address) -> bool {
+    let key = DataKey::Authorized(addr);
+    env.storage().persistent().get(&key).unwrap_or(true)
+}
+
+pub fn write_authorized(env: &Env, addr: Address, authorize: bool) {
+    let key = DataKey::Authorized(addr);
+    env.storage().persistent().set(&key, &authorize);
+}
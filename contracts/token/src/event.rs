@@ -0,0 +1,39 @@
+//! Standard SEP-41 token events, published so indexers and wallets can
+//! track balance-affecting operations without reading storage directly.
+
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub fn transfer(env: &Env, from: Address, to: Address, amount: i128) {
+    let topics = (symbol_short!("transfer"), from, to);
+    env.events().publish(topics, amount);
+}
+
+pub fn mint(env: &Env, admin: Address, to: Address, amount: i128) {
+    let topics = (symbol_short!("mint"), admin, to);
+    env.events().publish(topics, amount);
+}
+
+pub fn burn(env: &Env, from: Address, amount: i128) {
+    let topics = (symbol_short!("burn"), from);
+    env.events().publish(topics, amount);
+}
+
+pub fn approve(env: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+    let topics = (symbol_short!("approve"), from, spender);
+    env.events().publish(topics, (amount, expiration_ledger));
+}
+
+pub fn clawback(env: &Env, admin: Address, from: Address, amount: i128) {
+    let topics = (symbol_short!("clawback"), admin, from);
+    env.events().publish(topics, amount);
+}
+
+pub fn set_authorized(env: &Env, admin: Address, id: Address, authorize: bool) {
+    let topics = (symbol_short!("set_auth"), admin, id);
+    env.events().publish(topics, authorize);
+}
+
+pub fn set_admin(env: &Env, admin: Address, new_admin: Address) {
+    let topics = (symbol_short!("set_admin"), admin);
+    env.events().publish(topics, new_admin);
+}
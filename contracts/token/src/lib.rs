@@ -1,6 +1,32 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+mod admin;
+mod allowance;
+mod balance;
+mod event;
+mod metadata;
+mod storage_types;
+mod supply;
+#[cfg(test)]
+mod test;
+
+use admin::{has_administrator, read_administrator, write_administrator};
+use allowance::{read_allowance, spend_allowance, write_allowance};
+use balance::{
+    check_nonnegative_amount, read_balance, receive_balance, spend_balance,
+    spend_balance_no_authorization_check, write_authorized,
+};
+use metadata::{read_decimal, read_name, read_symbol, write_decimal, write_name, write_symbol};
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, String};
+use storage_types::AllowanceValue;
+use supply::{decrement_supply, increment_supply, read_supply, write_max_supply};
+
+contractmeta!(key = "Name", val = "SEP-41 Token Contract");
+contractmeta!(
+    key = "Description",
+    val = "SEP-41 fungible token with allowances, supply cap, and admin controls"
+);
+contractmeta!(key = "Version", val = "0.1.0");
 
 /// SEP-41 Token Contract
 /// Full implementation tracked in issues #1–#6
@@ -10,50 +36,152 @@ pub struct TokenContract;
 #[contractimpl]
 impl TokenContract {
     pub fn initialize(
-        _env: Env,
-        _admin: Address,
-        _decimal: u32,
-        _name: String,
-        _symbol: String,
-        _initial_supply: i128,
-        _max_supply: Option<i128>,
+        env: Env,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        initial_supply: i128,
+        max_supply: Option<i128>,
     ) {
-        todo!("implement initialize — see PRD section 6.2")
+        check_nonnegative_amount(initial_supply);
+        if has_administrator(&env) {
+            panic!("already initialized");
+        }
+        write_administrator(&env, &admin);
+        write_decimal(&env, decimal);
+        write_name(&env, &name);
+        write_symbol(&env, &symbol);
+        write_max_supply(&env, max_supply);
+
+        if initial_supply > 0 {
+            increment_supply(&env, initial_supply);
+            receive_balance(&env, admin.clone(), initial_supply);
+            event::mint(&env, admin.clone(), admin, initial_supply);
+        }
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = read_administrator(&env);
+        admin.require_auth();
+
+        increment_supply(&env, amount);
+        receive_balance(&env, to.clone(), amount);
+        event::mint(&env, admin, to, amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+
+        spend_balance(&env, from.clone(), amount);
+        decrement_supply(&env, amount);
+        event::burn(&env, from, amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+        spend_balance(&env, from.clone(), amount);
+        receive_balance(&env, to.clone(), amount);
+        event::transfer(&env, from, to, amount);
     }
 
-    pub fn mint(_env: Env, _to: Address, _amount: i128) {
-        todo!("implement mint — see issue #4 for max_supply enforcement")
+    /// Allows `spender` to transfer up to `amount` from `from`'s balance on
+    /// their behalf until `expiration_ledger`. Set `amount` to 0 to revoke.
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+        write_allowance(&env, from.clone(), spender.clone(), amount, expiration_ledger);
+        event::approve(&env, from, spender, amount, expiration_ledger);
     }
 
-    pub fn burn(_env: Env, _from: Address, _amount: i128) {
-        todo!()
+    /// Returns the live allowance `from -> spender`, or 0 once it has expired.
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        let AllowanceValue { amount, .. } = read_allowance(&env, from, spender);
+        amount
+    }
+
+    /// Transfers `amount` from `from` to `to`, authorized by `spender` acting
+    /// on a standing allowance rather than `from` directly.
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        spender.require_auth();
+        spend_allowance(&env, from.clone(), spender, amount);
+        spend_balance(&env, from.clone(), amount);
+        receive_balance(&env, to.clone(), amount);
+        event::transfer(&env, from, to, amount);
+    }
+
+    /// Admin-only: burns `amount` from `from` without its authorization.
+    /// Used to recover misissued or sanctioned tokens — including frozen
+    /// ones, which is the normal case for this function.
+    pub fn clawback(env: Env, admin: Address, from: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let stored_admin = read_administrator(&env);
+        stored_admin.require_auth();
+        if admin != stored_admin {
+            panic!("admin mismatch");
+        }
+
+        spend_balance_no_authorization_check(&env, from.clone(), amount);
+        decrement_supply(&env, amount);
+        event::clawback(&env, admin, from, amount);
+    }
+
+    /// Admin-only: freezes (`authorize: false`) or unfreezes an address.
+    /// Frozen accounts can neither send nor receive funds (transfer, transfer_from,
+    /// mint) nor burn.
+    pub fn set_authorized(env: Env, admin: Address, id: Address, authorize: bool) {
+        let stored_admin = read_administrator(&env);
+        stored_admin.require_auth();
+        if admin != stored_admin {
+            panic!("admin mismatch");
+        }
+
+        write_authorized(&env, id.clone(), authorize);
+        event::set_authorized(&env, admin, id, authorize);
+    }
+
+    /// Admin-only: transfers the administrator role to `new_admin`.
+    pub fn set_admin(env: Env, admin: Address, new_admin: Address) {
+        let stored_admin = read_administrator(&env);
+        stored_admin.require_auth();
+        if admin != stored_admin {
+            panic!("admin mismatch");
+        }
+
+        write_administrator(&env, &new_admin);
+        event::set_admin(&env, admin, new_admin);
     }
 
-    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {
-        todo!("see issue #1 for freeze check")
+    pub fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, id)
     }
 
-    pub fn balance(_env: Env, _id: Address) -> i128 {
-        todo!()
+    pub fn admin(env: Env) -> Address {
+        read_administrator(&env)
     }
 
-    pub fn admin(_env: Env) -> Address {
-        todo!()
+    pub fn decimals(env: Env) -> u32 {
+        read_decimal(&env)
     }
 
-    pub fn decimals(_env: Env) -> u32 {
-        todo!()
+    pub fn name(env: Env) -> String {
+        read_name(&env)
     }
 
-    pub fn name(_env: Env) -> String {
-        todo!()
+    pub fn symbol(env: Env) -> String {
+        read_symbol(&env)
     }
 
-    pub fn symbol(_env: Env) -> String {
-        todo!()
+    pub fn total_supply(env: Env) -> i128 {
+        read_supply(&env)
     }
 
-    pub fn total_supply(_env: Env) -> i128 {
-        todo!()
+    /// Contract semver, kept in sync with the `Version` metadata entry.
+    pub fn version(env: Env) -> String {
+        String::from_str(&env, "0.1.0")
     }
 }
@@ -0,0 +1,29 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Allowance entry: the granted amount and the ledger sequence at which it expires.
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Composite key identifying a `from -> spender` allowance.
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    Balance(Address),
+    Allowance(AllowanceDataKey),
+    Authorized(Address),
+}
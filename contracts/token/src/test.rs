@@ -0,0 +1,310 @@
+#![cfg(test)]
+
+use crate::{TokenContract, TokenContractClient};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, String};
+
+fn setup<'a>(env: &Env) -> (TokenContractClient<'a>, Address, Address) {
+    let (client, _contract_id, admin, to) = setup_with_id(env);
+    (client, admin, to)
+}
+
+fn setup_with_id<'a>(env: &Env) -> (TokenContractClient<'a>, Address, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(env, "Token"),
+        &String::from_str(env, "TOK"),
+        &1_000,
+        &None,
+    );
+    (client, contract_id, admin, Address::generate(env))
+}
+
+#[test]
+fn transfer_rejects_negative_amount() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+
+    assert!(client.try_transfer(&admin, &to, &-1).is_err());
+    assert_eq!(client.balance(&to), 0);
+}
+
+#[test]
+fn mint_rejects_negative_amount() {
+    let env = Env::default();
+    let (client, _admin, to) = setup(&env);
+
+    assert!(client.try_mint(&to, &-1).is_err());
+}
+
+#[test]
+fn burn_rejects_negative_amount() {
+    let env = Env::default();
+    let (client, admin, _to) = setup(&env);
+
+    assert!(client.try_burn(&admin, &-1).is_err());
+}
+
+#[test]
+fn transfer_from_rejects_negative_amount() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &1_000);
+    assert!(client.try_transfer_from(&spender, &admin, &to, &-1).is_err());
+}
+
+#[test]
+fn clawback_rejects_negative_amount() {
+    let env = Env::default();
+    let (client, admin, _to) = setup(&env);
+
+    assert!(client.try_clawback(&admin, &admin, &-1).is_err());
+}
+
+#[test]
+fn transfer_from_rejects_negative_amount_with_zero_allowance() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+    let spender = Address::generate(&env);
+
+    // No approve() call at all — allowance is zero.
+    assert!(client.try_transfer_from(&spender, &admin, &to, &-1).is_err());
+}
+
+#[test]
+fn allowance_reads_as_zero_once_expired() {
+    let env = Env::default();
+    let (client, admin, _to) = setup(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &10);
+    assert_eq!(client.allowance(&admin, &spender), 500);
+
+    env.ledger().with_mut(|l| l.sequence_number = 11);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+fn mint_rejects_when_exceeding_max_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Token"),
+        &String::from_str(&env, "TOK"),
+        &1_000,
+        &Some(1_000),
+    );
+
+    assert!(client.try_mint(&admin, &1).is_err());
+    assert_eq!(client.total_supply(), 1_000);
+}
+
+#[test]
+fn burn_rejects_negative_amount_even_under_cap() {
+    let env = Env::default();
+    let (client, admin, _to) = setup(&env);
+
+    // A negative burn must not be a backdoor to inflate total_supply past max_supply.
+    assert!(client.try_burn(&admin, &-1).is_err());
+    assert_eq!(client.total_supply(), 1_000);
+}
+
+#[test]
+fn transfer_rejects_receiving_into_deauthorized_account() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+
+    client.set_authorized(&admin, &to, &false);
+    assert!(client.try_transfer(&admin, &to, &1).is_err());
+}
+
+#[test]
+fn clawback_succeeds_against_frozen_account() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+
+    client.transfer(&admin, &to, &100);
+    client.set_authorized(&admin, &to, &false);
+
+    // The frozen account itself can no longer move funds...
+    assert!(client.try_transfer(&to, &admin, &1).is_err());
+    // ...but the admin can still claw them back.
+    client.clawback(&admin, &to, &100);
+    assert_eq!(client.balance(&to), 0);
+    assert_eq!(client.total_supply(), 900);
+}
+
+#[test]
+fn mint_rejects_into_deauthorized_account() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+
+    client.set_authorized(&admin, &to, &false);
+    assert!(client.try_mint(&to, &1).is_err());
+}
+
+#[test]
+fn transfer_emits_transfer_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, to) = setup_with_id(&env);
+
+    client.transfer(&admin, &to, &100);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("transfer"), admin, to).into_val(&env),
+                100i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn mint_emits_mint_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, to) = setup_with_id(&env);
+
+    client.mint(&to, &50);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("mint"), admin, to).into_val(&env),
+                50i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn burn_emits_burn_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, _to) = setup_with_id(&env);
+
+    client.burn(&admin, &50);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("burn"), admin).into_val(&env),
+                50i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn approve_emits_approve_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, _to) = setup_with_id(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &1_000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("approve"), admin, spender).into_val(&env),
+                (500i128, 1_000u32).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn clawback_emits_clawback_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, to) = setup_with_id(&env);
+
+    client.transfer(&admin, &to, &100);
+    client.clawback(&admin, &to, &100);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("clawback"), admin, to).into_val(&env),
+                100i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_authorized_emits_set_auth_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, to) = setup_with_id(&env);
+
+    client.set_authorized(&admin, &to, &false);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("set_auth"), admin, to).into_val(&env),
+                false.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_admin_emits_set_admin_event() {
+    let env = Env::default();
+    let (client, contract_id, admin, new_admin) = setup_with_id(&env);
+
+    client.set_admin(&admin, &new_admin);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id,
+                (symbol_short!("set_admin"), admin).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn transfer_from_rejects_spend_past_expired_allowance() {
+    let env = Env::default();
+    let (client, admin, to) = setup(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &10);
+    env.ledger().with_mut(|l| l.sequence_number = 11);
+
+    assert!(client.try_transfer_from(&spender, &admin, &to, &1).is_err());
+}
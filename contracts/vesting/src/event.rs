@@ -0,0 +1,14 @@
+//! Vesting events, published so indexers can track releases and revocations
+//! without replaying schedule storage.
+
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub fn release(env: &Env, recipient: Address, amount: i128) {
+    let topics = (symbol_short!("release"), recipient);
+    env.events().publish(topics, amount);
+}
+
+pub fn revoke(env: &Env, recipient: Address) {
+    let topics = (symbol_short!("revoke"), recipient);
+    env.events().publish(topics, ());
+}
@@ -1,6 +1,23 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env};
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contractimpl, contractmeta, token, Address, Env, String};
+use storage::{
+    has_administrator, has_schedule, next_schedule_id, read_administrator, read_schedule,
+    read_schedule_count, read_token_contract, write_administrator, write_schedule,
+    write_token_contract, Schedule,
+};
+
+contractmeta!(key = "Name", val = "Vesting Contract");
+contractmeta!(
+    key = "Description",
+    val = "Cliff + linear vesting of a SEP-41 token across multiple schedules per recipient"
+);
+contractmeta!(key = "Version", val = "0.1.0");
 
 /// Vesting Contract
 /// Full implementation tracked in issues #3, #5, #6
@@ -9,39 +26,169 @@ pub struct VestingContract;
 
 #[contractimpl]
 impl VestingContract {
-    pub fn initialize(_env: Env, _admin: Address, _token_contract: Address) {
-        todo!()
+    pub fn initialize(env: Env, admin: Address, token_contract: Address) {
+        if has_administrator(&env) {
+            panic!("already initialized");
+        }
+        write_administrator(&env, &admin);
+        write_token_contract(&env, &token_contract);
     }
 
-    /// Create a cliff + linear vesting schedule for a recipient.
+    /// Create a cliff + linear vesting schedule for a recipient and return
+    /// its schedule id. A recipient may hold several concurrent schedules,
+    /// e.g. an advisor cliff grant alongside a separate linear team grant.
     /// Ledger numbers used instead of timestamps.
     pub fn create_schedule(
-        _env: Env,
-        _recipient: Address,
-        _total_amount: i128,
-        _cliff_ledger: u32,
-        _end_ledger: u32,
-    ) {
-        todo!()
+        env: Env,
+        recipient: Address,
+        total_amount: i128,
+        cliff_ledger: u32,
+        end_ledger: u32,
+    ) -> u32 {
+        read_administrator(&env).require_auth();
+
+        if end_ledger <= cliff_ledger {
+            panic!("end_ledger must be after cliff_ledger");
+        }
+
+        let schedule_id = next_schedule_id(&env, &recipient);
+        let schedule = Schedule {
+            total_amount,
+            start_ledger: env.ledger().sequence(),
+            cliff_ledger,
+            end_ledger,
+            released: 0,
+        };
+        write_schedule(&env, &recipient, schedule_id, &schedule);
+        schedule_id
+    }
+
+    /// Release the currently vested, not-yet-released tokens for one
+    /// schedule. Can be called by anyone.
+    pub fn release(env: Env, recipient: Address, schedule_id: u32) {
+        let releasable = Self::release_schedule(&env, &recipient, schedule_id);
+        if releasable > 0 {
+            event::release(&env, recipient, releasable);
+        }
     }
 
-    /// Release all currently vested tokens to recipient.
-    /// Can be called by anyone.
-    pub fn release(_env: Env, _recipient: Address) {
-        todo!()
+    /// Release the currently vested, not-yet-released tokens across every
+    /// schedule held by recipient, in one call.
+    pub fn release_all(env: Env, recipient: Address) {
+        let mut total = 0i128;
+        for schedule_id in 0..read_schedule_count(&env, &recipient) {
+            if has_schedule(&env, &recipient, schedule_id) {
+                total += Self::release_schedule(&env, &recipient, schedule_id);
+            }
+        }
+        if total > 0 {
+            event::release(&env, recipient, total);
+        }
     }
 
     /// Admin-only: revoke a schedule, send vested portion to recipient,
     /// return remainder to admin. See issue #3.
-    pub fn revoke(_env: Env, _recipient: Address) {
-        todo!("implement revoke — see issue #3")
+    pub fn revoke(env: Env, recipient: Address, schedule_id: u32) {
+        read_administrator(&env).require_auth();
+
+        Self::release(env.clone(), recipient.clone(), schedule_id);
+
+        let schedule = read_schedule(&env, &recipient, schedule_id);
+        let remainder = schedule.total_amount - schedule.released;
+        if remainder > 0 {
+            let token_client = token::Client::new(&env, &read_token_contract(&env));
+            token_client.transfer(
+                &env.current_contract_address(),
+                &read_administrator(&env),
+                &remainder,
+            );
+        }
+
+        // Pin cliff/end to now so `schedule_vested_amount` takes the "fully
+        // vested" branch immediately — otherwise it would keep computing a
+        // fraction of the now-shrunk total_amount and under-report what was
+        // already paid out for the rest of the schedule's original life.
+        let current_ledger = env.ledger().sequence();
+        write_schedule(
+            &env,
+            &recipient,
+            schedule_id,
+            &Schedule {
+                total_amount: schedule.released,
+                cliff_ledger: current_ledger,
+                end_ledger: current_ledger,
+                ..schedule
+            },
+        );
+        event::revoke(&env, recipient);
+    }
+
+    pub fn vested_amount(env: Env, recipient: Address, schedule_id: u32) -> i128 {
+        if !has_schedule(&env, &recipient, schedule_id) {
+            return 0;
+        }
+        let schedule = read_schedule(&env, &recipient, schedule_id);
+        Self::schedule_vested_amount(&env, &schedule)
+    }
+
+    pub fn released_amount(env: Env, recipient: Address, schedule_id: u32) -> i128 {
+        if !has_schedule(&env, &recipient, schedule_id) {
+            return 0;
+        }
+        read_schedule(&env, &recipient, schedule_id).released
+    }
+
+    /// Full schedule details in one call, for explorers and tooling that
+    /// would otherwise have to stitch together several getters. Returns
+    /// `None` for an unknown `(recipient, schedule_id)`, matching
+    /// `vested_amount`/`released_amount` rather than panicking.
+    pub fn schedule_info(env: Env, recipient: Address, schedule_id: u32) -> Option<Schedule> {
+        if !has_schedule(&env, &recipient, schedule_id) {
+            return None;
+        }
+        Some(read_schedule(&env, &recipient, schedule_id))
     }
 
-    pub fn vested_amount(_env: Env, _recipient: Address) -> i128 {
-        todo!()
+    /// Contract semver, kept in sync with the `Version` metadata entry.
+    pub fn version(env: Env) -> String {
+        String::from_str(&env, "0.1.0")
     }
 
-    pub fn released_amount(_env: Env, _recipient: Address) -> i128 {
-        todo!()
+    fn schedule_vested_amount(env: &Env, schedule: &Schedule) -> i128 {
+        let current_ledger = env.ledger().sequence();
+
+        if current_ledger < schedule.cliff_ledger {
+            0
+        } else if current_ledger >= schedule.end_ledger {
+            schedule.total_amount
+        } else {
+            // Multiply before dividing so the fraction isn't truncated away.
+            schedule.total_amount * (current_ledger - schedule.start_ledger) as i128
+                / (schedule.end_ledger - schedule.start_ledger) as i128
+        }
+    }
+
+    /// Transfers the vested-minus-released delta for one schedule and
+    /// records it as released. Returns the amount actually transferred.
+    fn release_schedule(env: &Env, recipient: &Address, schedule_id: u32) -> i128 {
+        let schedule = read_schedule(env, recipient, schedule_id);
+        let releasable = Self::schedule_vested_amount(env, &schedule) - schedule.released;
+        if releasable <= 0 {
+            return 0;
+        }
+
+        let token_client = token::Client::new(env, &read_token_contract(env));
+        token_client.transfer(&env.current_contract_address(), recipient, &releasable);
+
+        write_schedule(
+            env,
+            recipient,
+            schedule_id,
+            &Schedule {
+                released: schedule.released + releasable,
+                ..schedule
+            },
+        );
+        releasable
     }
 }
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Schedule {
+    pub total_amount: i128,
+    pub start_ledger: u32,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+    pub released: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Admin,
+    TokenContract,
+    ScheduleCount(Address),
+    Schedule(Address, u32),
+}
+
+pub fn has_administrator(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn read_administrator(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub fn write_administrator(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn read_token_contract(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenContract)
+        .unwrap()
+}
+
+pub fn write_token_contract(env: &Env, token_contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenContract, token_contract);
+}
+
+/// Number of schedules ever created for `recipient`; also the next free id.
+pub fn read_schedule_count(env: &Env, recipient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ScheduleCount(recipient.clone()))
+        .unwrap_or(0)
+}
+
+fn write_schedule_count(env: &Env, recipient: &Address, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ScheduleCount(recipient.clone()), &count);
+}
+
+/// Allocates the next schedule id for `recipient`.
+pub fn next_schedule_id(env: &Env, recipient: &Address) -> u32 {
+    let id = read_schedule_count(env, recipient);
+    write_schedule_count(env, recipient, id + 1);
+    id
+}
+
+pub fn has_schedule(env: &Env, recipient: &Address, schedule_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Schedule(recipient.clone(), schedule_id))
+}
+
+pub fn read_schedule(env: &Env, recipient: &Address, schedule_id: u32) -> Schedule {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Schedule(recipient.clone(), schedule_id))
+        .unwrap()
+}
+
+pub fn write_schedule(env: &Env, recipient: &Address, schedule_id: u32, schedule: &Schedule) {
+    env.storage().persistent().set(
+        &DataKey::Schedule(recipient.clone(), schedule_id),
+        schedule,
+    );
+}
@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+use crate::{VestingContract, VestingContractClient};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, String};
+use token::{TokenContract, TokenContractClient};
+
+fn setup(env: &Env) -> (VestingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, VestingContract);
+    let client = VestingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_contract = Address::generate(env);
+    client.initialize(&admin, &token_contract);
+    (client, admin, Address::generate(env))
+}
+
+/// Like `setup`, but backs the vesting contract with a real `TokenContract`
+/// instead of a placeholder address, and funds the vesting contract's
+/// balance so `release`/`release_all`/`revoke` can actually move tokens.
+fn setup_with_token(
+    env: &Env,
+    funding: i128,
+) -> (VestingContractClient<'_>, TokenContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(env);
+    let token_id = env.register_contract(None, TokenContract);
+    let token_client = TokenContractClient::new(env, &token_id);
+    token_client.initialize(
+        &token_admin,
+        &7,
+        &String::from_str(env, "Token"),
+        &String::from_str(env, "TOK"),
+        &0,
+        &None,
+    );
+
+    let vesting_id = env.register_contract(None, VestingContract);
+    let vesting_client = VestingContractClient::new(env, &vesting_id);
+    let vesting_admin = Address::generate(env);
+    vesting_client.initialize(&vesting_admin, &token_id);
+
+    token_client.mint(&vesting_id, &funding);
+
+    (vesting_client, token_client, vesting_admin, Address::generate(env))
+}
+
+#[test]
+fn vested_amount_is_zero_before_cliff() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+    assert_eq!(client.vested_amount(&recipient, &id), 0);
+}
+
+#[test]
+fn vested_amount_is_linear_between_cliff_and_end() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+
+    // Halfway from start_ledger(100) to end_ledger(300).
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+    assert_eq!(client.vested_amount(&recipient, &id), 500);
+
+    // Three quarters of the way through.
+    env.ledger().with_mut(|l| l.sequence_number = 250);
+    assert_eq!(client.vested_amount(&recipient, &id), 750);
+}
+
+#[test]
+fn vested_amount_is_total_at_and_after_end() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+
+    env.ledger().with_mut(|l| l.sequence_number = 300);
+    assert_eq!(client.vested_amount(&recipient, &id), 1_000);
+
+    env.ledger().with_mut(|l| l.sequence_number = 1_000);
+    assert_eq!(client.vested_amount(&recipient, &id), 1_000);
+}
+
+#[test]
+fn create_schedule_rejects_end_at_or_before_cliff() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+
+    assert!(client
+        .try_create_schedule(&recipient, &1_000, &300, &300)
+        .is_err());
+    assert!(client
+        .try_create_schedule(&recipient, &1_000, &300, &200)
+        .is_err());
+}
+
+#[test]
+fn multiple_schedules_per_recipient_are_independent() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+
+    let advisor_id = client.create_schedule(&recipient, &1_000, &100, &200);
+    let team_id = client.create_schedule(&recipient, &4_000, &200, &400);
+
+    assert_ne!(advisor_id, team_id);
+
+    env.ledger().with_mut(|l| l.sequence_number = 150);
+    assert_eq!(client.vested_amount(&recipient, &advisor_id), 500);
+    assert_eq!(client.vested_amount(&recipient, &team_id), 0);
+}
+
+#[test]
+fn release_transfers_vested_tokens_to_recipient() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    let (client, token, _admin, recipient) = setup_with_token(&env, 1_000);
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+
+    client.release(&recipient, &id);
+
+    assert_eq!(token.balance(&recipient), 500);
+    assert_eq!(client.released_amount(&recipient, &id), 500);
+
+    // Calling again before any more vesting accrues pays out nothing extra.
+    client.release(&recipient, &id);
+    assert_eq!(token.balance(&recipient), 500);
+}
+
+#[test]
+fn release_all_sums_every_schedule_for_recipient() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    let (client, token, _admin, recipient) = setup_with_token(&env, 5_000);
+
+    let advisor_id = client.create_schedule(&recipient, &1_000, &100, &200);
+    let team_id = client.create_schedule(&recipient, &4_000, &200, &400);
+
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+    client.release_all(&recipient);
+
+    // Advisor schedule is fully vested (1_000); team schedule just hit its cliff,
+    // so it has vested linearly from its start: 4_000 * 100 / 300 = 1_333.
+    assert_eq!(token.balance(&recipient), 2_333);
+    assert_eq!(client.released_amount(&recipient, &advisor_id), 1_000);
+    assert_eq!(client.released_amount(&recipient, &team_id), 1_333);
+}
+
+#[test]
+fn revoke_pays_vested_portion_to_recipient_and_remainder_to_admin() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    let (client, token, admin, recipient) = setup_with_token(&env, 1_000);
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+
+    client.revoke(&recipient, &id);
+
+    assert_eq!(token.balance(&recipient), 500);
+    assert_eq!(token.balance(&admin), 500);
+    assert_eq!(client.released_amount(&recipient, &id), 500);
+
+    // Post-revoke, the schedule must report exactly what was paid out, not a
+    // fraction of the shrunk total, for the rest of its original life.
+    assert_eq!(client.vested_amount(&recipient, &id), 500);
+    env.ledger().with_mut(|l| l.sequence_number = 250);
+    assert_eq!(client.vested_amount(&recipient, &id), 500);
+}
+
+#[test]
+fn getters_report_missing_schedule_without_panicking() {
+    let env = Env::default();
+    let (client, _admin, recipient) = setup(&env);
+
+    assert_eq!(client.vested_amount(&recipient, &0), 0);
+    assert_eq!(client.released_amount(&recipient, &0), 0);
+    assert_eq!(client.schedule_info(&recipient, &0), None);
+}
+
+#[test]
+fn release_emits_release_event() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    let (client, token, _admin, recipient) = setup_with_token(&env, 1_000);
+    let contract_id = client.address.clone();
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+    client.release(&recipient, &id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("transfer"), contract_id.clone(), recipient.clone()).into_val(&env),
+                500i128.into_val(&env),
+            ),
+            (
+                contract_id,
+                (symbol_short!("release"), recipient).into_val(&env),
+                500i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn revoke_emits_revoke_event() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    let (client, token, admin, recipient) = setup_with_token(&env, 1_000);
+    let contract_id = client.address.clone();
+
+    let id = client.create_schedule(&recipient, &1_000, &200, &300);
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+    client.revoke(&recipient, &id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                token.address.clone(),
+                (symbol_short!("transfer"), contract_id.clone(), recipient.clone()).into_val(&env),
+                500i128.into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("release"), recipient.clone()).into_val(&env),
+                500i128.into_val(&env),
+            ),
+            (
+                token.address.clone(),
+                (symbol_short!("transfer"), contract_id.clone(), admin).into_val(&env),
+                500i128.into_val(&env),
+            ),
+            (
+                contract_id,
+                (symbol_short!("revoke"), recipient).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+}